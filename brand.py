@@ -34,16 +34,19 @@ class Record:
         for field in Record.fields:
             setattr(self, field, Record.empty)
 
+        self.line = ''
+
     @classmethod
     def create_from(cls, line: str):
         record = cls()
+        record.line = line
 
         for (k, v) in zip(Record.fields, line.split('\t')):
             setattr(record, k, v)
 
         return record
 
-    def export(self):
+    def export(self, filename: Path, template: dict):
         coordinates = {}
 
         if self.coordinates and self.coordinates != Record.empty:
@@ -56,16 +59,25 @@ class Record:
                     "GPSLongitudeRef": 'W',
                 }
             except:
-                logging.error("Badly formatted coordinates: %s",
-                              self.coordinates)
+                logging.error("Badly formatted coordinates for '%s': %s",
+                              filename, self.coordinates)
 
-        return coordinates | {
+        values = {
             "shutterspeed": self.sspeed,
             "ApertureValue": self.fnumber,
             "FNumber": self.fnumber,
             "focallength": self.flength,
             "Description": self.comment,
             "alldates": self.date,
+            "UserComment": self.line,
+        }
+
+        defaults = {tag.lower() for tag in template}
+
+        return coordinates | {
+            k: v
+            for (k, v) in values.items()
+            if v != Record.empty and (v or k.lower() not in defaults)
         }
 
 
@@ -84,6 +96,10 @@ def read_tse(filename: Path):
         els = field.split()
         template[els[0].strip('#')] = ' '.join(els[1:])
 
+    tags = {tag.lower(): tag for tag in template}
+    if 'fnumber' in tags and 'aperturevalue' not in tags:
+        template['ApertureValue'] = template[tags['fnumber']]
+
     records = {}
     entries = filter(lambda l: l[0] not in ['#', ';'], lines)
     for index, value in enumerate(entries, start=1):
@@ -107,9 +123,14 @@ def main(negatives_dir: Path, tse_file: Path):
             logging.error("Missing exposure record for file '%s'", filename)
             continue
 
-        data = template.copy()
+        values = records[index].export(filename, template)
+        overridden = {tag.lower() for tag in values}
 
-        data.update(records[index].export())
+        data = {
+            k: v
+            for (k, v) in template.items() if k.lower() not in overridden
+        }
+        data.update(values)
 
         with NamedTemporaryFile('w+', delete=False) as datafile:
             json.dump(data, datafile)
@@ -120,12 +141,15 @@ def main(negatives_dir: Path, tse_file: Path):
 
     for filename, data in todo:
         command = f"exiftool -m -q -j={data} {filename.resolve()}"
-        processes.append(Popen(split(command), stdout=PIPE, stderr=PIPE))
+        processes.append(
+            (filename, Popen(split(command), stdout=PIPE, stderr=PIPE)))
+
+    for filename, proc in processes:
+        _, err = proc.communicate()
 
-    for proc in processes:
-        if status := proc.wait():
-            logging.critical("Process %d failed with status %d: %s", proc.pid,
-                             proc.returncode, proc.stderr)
+        if proc.returncode:
+            logging.critical("Tagging '%s' failed with status %d: %s",
+                             filename, proc.returncode, err.decode().strip())
 
     for _, datafile in todo:
         if Path(datafile).exists():
@@ -4,31 +4,51 @@ import sys
 import logging
 from shutil import which
 from shlex import split
-from PIL import Image
+from PIL import Image, ImageSequence
 from subprocess import Popen, PIPE
 from pathlib import Path
 
 
-def compress_clean(imagefile: Path, processes: list[Popen]):
+def largest_page(imagefile: Path):
+    size = (None, None)
+
+    try:
+        with Image.open(imagefile) as image:
+            size = image.size
+            pages = [(page.size, index) for index, page in enumerate(
+                ImageSequence.Iterator(image))]
+    except Exception as err:
+        logging.warning("Failed to probe pages of '%s', using page 0: %s",
+                        imagefile, err)
+        return 0, *size
+
+    (width, height), index = max(pages, key=lambda p: p[0][0] * p[0][1])
+    return index, width, height
+
+
+def compress_clean(imagefile: Path, processes: list[tuple[Path, Popen]]):
     magick_tool = which('convert')
+    page, _, _ = largest_page(imagefile)
 
-    convert_tiff = f"""{magick_tool} {imagefile.resolve()}[0] -compress lzw "{imagefile.with_suffix('.tiff')}" """
+    convert_tiff = f"""{magick_tool} {imagefile.resolve()}[{page}] -compress lzw "{imagefile.with_suffix('.tiff')}" """
 
     logging.debug("TIFF: %s", split(convert_tiff))
 
-    processes.append(Popen(split(convert_tiff), stdout=PIPE, stderr=PIPE))
+    processes.append(
+        (imagefile, Popen(split(convert_tiff), stdout=PIPE, stderr=PIPE)))
 
 
-def create_thumb(imagefile: Path, processes: list[Popen]):
+def create_thumb(imagefile: Path, processes: list[tuple[Path, Popen]]):
     magick_tool = which('convert')
-    image = Image.open(imagefile)
-    width, height = image.size
+    page, width, height = largest_page(imagefile)
+    geometry = f'"{width//2}"x"{height//2}"' if width else '50%'
 
-    convert_jpg = f"""{magick_tool} {imagefile.resolve()}[0] -resize "{width//2}"x"{height//2}" "{imagefile.with_suffix('.jpg')}" """
+    convert_jpg = f"""{magick_tool} {imagefile.resolve()}[{page}] -resize {geometry} "{imagefile.with_suffix('.jpg')}" """
 
     logging.debug("JPG: %s", split(convert_jpg))
 
-    processes.append(Popen(split(convert_jpg), stdout=PIPE, stderr=PIPE))
+    processes.append(
+        (imagefile, Popen(split(convert_jpg), stdout=PIPE, stderr=PIPE)))
 
 
 def apply_transformation(reg, transform):
@@ -38,16 +58,17 @@ def apply_transformation(reg, transform):
         logging.debug("Converting '%s'", file)
         transform(file, conversions)
 
-    for proc in conversions:
-        status = proc.wait()
+    for file, proc in conversions:
+        _, err = proc.communicate()
 
-        if status:
-            logging.critical(f"Process %d failed with error code %d: %s",
-                             proc.pid, proc.returncode, proc.stderr)
+        if proc.returncode:
+            logging.critical("Converting '%s' failed with status %d: %s",
+                             file, proc.returncode, err.decode().strip())
 
 
 if __name__ == '__main__':
     logging.basicConfig(level=logging.DEBUG)
+    Image.MAX_IMAGE_PIXELS = None
     directory = Path(sys.argv[1])
 
     apply_transformation('*tif', compress_clean)